@@ -0,0 +1,334 @@
+//! Compile-time evaluable counterparts of [`crate::ArrayUtil`] and
+//! [`crate::flatten`].
+//!
+//! Everything in this module requires `T: Copy` and stays a `const fn` by
+//! bitwise-copying elements out of `self` instead of moving them, so no
+//! destructor ever needs to run (destructors can't run in const contexts).
+//! Reach for [`crate::ArrayUtil`] instead when `T` isn't `Copy`, or when a
+//! `const` context isn't needed.
+
+use std::mem::MaybeUninit;
+
+const unsafe fn assume_init<T: Copy, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
+    (&array as *const _ as *const [T; N]).read()
+}
+
+/// Creates an array that flattens a nested structure.
+///
+/// # Examples
+/// ```
+/// let array = [[1u8, 2], [3, 4]];
+/// let flattened = array_util::konst::flatten(array);
+/// assert_eq!(flattened, [1u8, 2, 3, 4]);
+/// ```
+pub const fn flatten<T: Copy, const A: usize, const B: usize>(array: [[T; B]; A]) -> [T; A * B] {
+    let mut data: [_; A * B] = MaybeUninit::uninit_array();
+    let mut pos = 0;
+    while pos < A {
+        let inner = array[pos];
+        let mut i = 0;
+
+        while i < B {
+            data[pos * B + i] = MaybeUninit::new(inner[i]);
+            i += 1;
+        }
+
+        pos += 1
+    }
+    // Safety: data was fully initialized
+    unsafe { assume_init(data) }
+}
+
+/// Creates an array by calling `f` with each index in `0..N`.
+///
+/// # Examples
+/// ```
+/// const ARRAY: [usize; 4] = array_util::konst::from_fn(|i| i * 2);
+/// assert_eq!(ARRAY, [0, 2, 4, 6]);
+/// ```
+pub const fn from_fn<T: Copy, const N: usize, F>(mut f: F) -> [T; N]
+where
+    F: ~const FnMut(usize) -> T,
+{
+    let mut data: [_; N] = MaybeUninit::uninit_array();
+
+    let mut i = 0;
+    while i < N {
+        data[i] = MaybeUninit::new(f(i));
+        i += 1;
+    }
+
+    // Safety: data was fully initialized
+    unsafe { assume_init(data) }
+}
+
+/// A `const`-evaluable, `T: Copy` fast path for the operations in
+/// [`crate::ArrayUtil`]. See the crate-level trait for the general, non-`Copy`
+/// versions.
+pub trait ConstArrayUtil {
+    type Item: Copy;
+    const LEN: usize;
+
+    /// Creates a new array with the last element removed.
+    fn pop(self) -> [Self::Item; Self::LEN - 1];
+
+    /// Creates a new array with an additional element at the back.
+    fn push(self, value: Self::Item) -> [Self::Item; Self::LEN + 1];
+
+    /// Creates a new array without the element at position `index`,
+    /// shifting all elements after it to the left.
+    fn remove(self, index: usize) -> [Self::Item; Self::LEN - 1];
+
+    /// Creates a new array with the order of elements reversed.
+    fn reverse(self) -> [Self::Item; Self::LEN];
+
+    /// Divides one array into two at an index.
+    ///
+    /// The first will contain all indices from `[0, POS)` (excluding
+    /// the index `POS` itself) and the second will contain all
+    /// indices from `[POS, len)` (excluding the index `len` itself).
+    fn split<const POS: usize>(self) -> ([Self::Item; POS], [Self::Item; Self::LEN - POS]);
+
+    /// Regroups a flat array into fixed-size sub-arrays, the inverse of
+    /// [`flatten`].
+    ///
+    /// # Panics
+    /// Panics if `Self::LEN` isn't evenly divisible by `C`.
+    fn chunks<const C: usize>(self) -> [[Self::Item; C]; Self::LEN / C];
+
+    /// Joins `self` with `other`, the counterpart to [`ConstArrayUtil::split`].
+    fn concat<const M: usize>(self, other: [Self::Item; M]) -> [Self::Item; Self::LEN + M];
+
+    /// Creates a new array with `value` inserted at position `index`,
+    /// shifting all elements at and after it to the right. The counterpart
+    /// to [`ConstArrayUtil::remove`].
+    fn insert(self, index: usize, value: Self::Item) -> [Self::Item; Self::LEN + 1];
+
+    /// Creates a new array by applying `f` to each element of `self`.
+    fn map<U: Copy, F>(self, f: F) -> [U; Self::LEN]
+    where
+        F: ~const FnMut(Self::Item) -> U;
+}
+
+impl<T, const N: usize> const ConstArrayUtil for [T; N]
+where
+    T: Copy,
+{
+    type Item = T;
+    const LEN: usize = N;
+
+    fn pop(self) -> [Self::Item; Self::LEN - 1] {
+        self.remove(Self::LEN - 1)
+    }
+
+    fn push(self, value: Self::Item) -> [Self::Item; Self::LEN + 1] {
+        let mut data: [_; Self::LEN + 1] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < Self::LEN {
+            data[pos] = MaybeUninit::new(self[pos]);
+            pos += 1
+        }
+        data[N] = MaybeUninit::new(value);
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn remove(self, index: usize) -> [Self::Item; Self::LEN - 1] {
+        assert!(index < Self::LEN);
+
+        let mut data: [_; Self::LEN - 1] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        let mut i = 0;
+        while pos < Self::LEN {
+            if pos != index {
+                data[i] = MaybeUninit::new(self[pos]);
+                i += 1;
+            }
+            pos += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn reverse(self) -> [Self::Item; Self::LEN] {
+        let mut data: [_; Self::LEN] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < Self::LEN {
+            data[Self::LEN - pos - 1] = MaybeUninit::new(self[pos]);
+            pos += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn split<const POS: usize>(self) -> ([Self::Item; POS], [Self::Item; Self::LEN - POS]) {
+        let mut a: [_; POS] = MaybeUninit::uninit_array();
+        let mut b: [_; Self::LEN - POS] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < a.len() {
+            a[pos] = MaybeUninit::new(self[pos]);
+            pos += 1
+        }
+
+        while pos < Self::LEN {
+            b[pos - POS] = MaybeUninit::new(self[pos]);
+            pos += 1
+        }
+
+        // Safety: both a and b were fully initialized
+        unsafe { (assume_init(a), assume_init(b)) }
+    }
+
+    fn chunks<const C: usize>(self) -> [[Self::Item; C]; Self::LEN / C] {
+        assert!(Self::LEN % C == 0);
+
+        let mut data: [_; Self::LEN / C] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < Self::LEN / C {
+            let mut inner: [_; C] = MaybeUninit::uninit_array();
+            let mut i = 0;
+            while i < C {
+                inner[i] = MaybeUninit::new(self[pos * C + i]);
+                i += 1;
+            }
+            // Safety: inner was fully initialized
+            data[pos] = MaybeUninit::new(unsafe { assume_init(inner) });
+            pos += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn concat<const M: usize>(self, other: [Self::Item; M]) -> [Self::Item; Self::LEN + M] {
+        let mut data: [_; Self::LEN + M] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < Self::LEN {
+            data[pos] = MaybeUninit::new(self[pos]);
+            pos += 1;
+        }
+        let mut i = 0;
+        while i < M {
+            data[Self::LEN + i] = MaybeUninit::new(other[i]);
+            i += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn insert(self, index: usize, value: Self::Item) -> [Self::Item; Self::LEN + 1] {
+        assert!(index <= Self::LEN);
+
+        let mut data: [_; Self::LEN + 1] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < index {
+            data[pos] = MaybeUninit::new(self[pos]);
+            pos += 1;
+        }
+        data[index] = MaybeUninit::new(value);
+        while pos < Self::LEN {
+            data[pos + 1] = MaybeUninit::new(self[pos]);
+            pos += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn map<U: Copy, F>(self, mut f: F) -> [U; Self::LEN]
+    where
+        F: ~const FnMut(Self::Item) -> U,
+    {
+        let mut data: [_; Self::LEN] = MaybeUninit::uninit_array();
+
+        let mut pos = 0;
+        while pos < Self::LEN {
+            data[pos] = MaybeUninit::new(f(self[pos]));
+            pos += 1;
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstArrayUtil;
+
+    // TODO remove: The compiler currently panics when trying to
+    // use `==` on the returned arrays
+    fn eq<T: Eq>(a: &[T], b: &[T]) -> bool {
+        a.iter().eq(b.iter())
+    }
+
+    #[test]
+    fn flatten() {
+        assert!(eq(&super::flatten([[1, 2], [3, 4]]), &[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn pop() {
+        assert!(eq(&[1, 2, 3].pop(), &[1, 2]));
+    }
+
+    #[test]
+    fn push() {
+        assert!(eq(&[1, 2].push(3), &[1, 2, 3]));
+    }
+
+    #[test]
+    fn remove() {
+        assert!(eq(&[1, 2, 3].remove(1), &[1, 3]));
+    }
+
+    #[test]
+    fn reverse() {
+        assert!(eq(&[1, 2, 3].reverse(), &[3, 2, 1]));
+    }
+
+    #[test]
+    fn split() {
+        let (a, b) = [1, 2, 3].split::<2>();
+        assert!(eq(&a, &[1, 2]));
+        assert!(eq(&b, &[3]));
+    }
+
+    #[test]
+    fn chunks() {
+        let chunks = [1, 2, 3, 4].chunks::<2>();
+        assert!(eq(&chunks[0], &[1, 2]));
+        assert!(eq(&chunks[1], &[3, 4]));
+    }
+
+    #[test]
+    fn concat() {
+        assert!(eq(&[1, 2].concat([3, 4, 5]), &[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn insert() {
+        assert!(eq(&[1, 2, 4].insert(2, 3), &[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn from_fn() {
+        assert!(eq(&super::from_fn::<_, 4, _>(|i| i * 2), &[0, 2, 4, 6]));
+    }
+
+    #[test]
+    fn map() {
+        assert!(eq(&ConstArrayUtil::map([1, 2, 3], |x| x * 2), &[2, 4, 6]));
+    }
+}
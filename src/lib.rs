@@ -2,6 +2,7 @@
 #![feature(
     const_evaluatable_checked,
     const_fn,
+    const_fn_trait_bound,
     const_generics,
     const_panic,
     const_ptr_read,
@@ -11,40 +12,76 @@
     maybe_uninit_array_assume_init
 )]
 
-use std::mem::MaybeUninit;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
 
-const unsafe fn assume_init<T: Copy, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
-    (&array as *const _ as *const [T; N]).read()
+pub mod konst;
+
+/// Safety: every element of `buf` must have been initialized exactly once.
+unsafe fn assume_init<T, const N: usize>(buf: [MaybeUninit<T>; N]) -> [T; N] {
+    MaybeUninit::array_assume_init(buf)
 }
 
 /// Creates an array that flattens a nested structure.
 ///
+/// Works for any `T`, including types that aren't `Copy`. Use
+/// [`konst::flatten`] instead if you need a `const fn`.
+///
 /// # Examples
 /// ```
 /// let array = [[1u8, 2], [3, 4]];
 /// let flattened = array_util::flatten(array);
 /// assert_eq!(flattened, [1u8, 2, 3, 4]);
 /// ```
-pub const fn flatten<T: Copy, const A: usize, const B: usize>(array: [[T; B]; A]) -> [T; A * B] {
-    let mut data: [_; A * B] = MaybeUninit::uninit_array();
-    let mut pos = 0;
-    while pos < A {
-        let inner = array[pos];
-        let mut i = 0;
-
-        while i < B {
-            data[pos * B + i] = MaybeUninit::new(inner[i]);
-            i += 1;
+pub fn flatten<T, const A: usize, const B: usize>(array: [[T; B]; A]) -> [T; A * B] {
+    let array = ManuallyDrop::new(array);
+    let mut data: [MaybeUninit<T>; A * B] = MaybeUninit::uninit_array();
+
+    for pos in 0..A {
+        for i in 0..B {
+            // Safety: each (pos, i) pair is written to exactly once, and
+            // `array` is a `ManuallyDrop` so the moved-out element is never
+            // dropped a second time when it goes out of scope.
+            data[pos * B + i] = MaybeUninit::new(unsafe { ptr::read(&array[pos][i]) });
         }
+    }
 
-        pos += 1
+    // Safety: data was fully initialized
+    unsafe { assume_init(data) }
+}
+
+/// Creates an array by calling `f` with each index in `0..N`.
+///
+/// Works for any `T`; see [`konst::from_fn`] for a `const fn` version that
+/// requires `T: Copy`.
+///
+/// # Examples
+/// ```
+/// let array = array_util::from_fn::<_, 4, _>(|i| i * 2);
+/// assert_eq!(array, [0, 2, 4, 6]);
+/// ```
+pub fn from_fn<T, const N: usize, F>(mut f: F) -> [T; N]
+where
+    F: FnMut(usize) -> T,
+{
+    let mut data: [MaybeUninit<T>; N] = MaybeUninit::uninit_array();
+
+    for i in 0..N {
+        data[i] = MaybeUninit::new(f(i));
     }
+
     // Safety: data was fully initialized
     unsafe { assume_init(data) }
 }
 
+/// Operations on owned, fixed-size arrays that return a new array rather
+/// than mutating in place.
+///
+/// These work for any `Self::Item`, including types that aren't `Copy`,
+/// by moving each element out of `self` exactly once. See [`konst`] for
+/// `const fn` versions that require `Self::Item: Copy`.
 pub trait ArrayUtil {
-    type Item: Copy;
+    type Item;
     const LEN: usize;
 
     /// Creates a new array with the last element removed.
@@ -66,12 +103,29 @@ pub trait ArrayUtil {
     /// the index `POS` itself) and the second will contain all
     /// indices from `[mid, len)` (excluding the index `len` itself).
     fn split<const POS: usize>(self) -> ([Self::Item; POS], [Self::Item; Self::LEN - POS]);
+
+    /// Regroups a flat array into fixed-size sub-arrays, the inverse of
+    /// [`flatten`].
+    ///
+    /// # Panics
+    /// Panics if `Self::LEN` isn't evenly divisible by `C`.
+    fn chunks<const C: usize>(self) -> [[Self::Item; C]; Self::LEN / C];
+
+    /// Joins `self` with `other`, the counterpart to [`ArrayUtil::split`].
+    fn concat<const M: usize>(self, other: [Self::Item; M]) -> [Self::Item; Self::LEN + M];
+
+    /// Creates a new array with `value` inserted at position `index`,
+    /// shifting all elements at and after it to the right. The counterpart
+    /// to [`ArrayUtil::remove`].
+    fn insert(self, index: usize, value: Self::Item) -> [Self::Item; Self::LEN + 1];
+
+    /// Creates a new array by applying `f` to each element of `self`.
+    fn map<U, F>(self, f: F) -> [U; Self::LEN]
+    where
+        F: FnMut(Self::Item) -> U;
 }
 
-impl<T, const N: usize> const ArrayUtil for [T; N]
-where
-    T: Copy,
-{
+impl<T, const N: usize> ArrayUtil for [T; N] {
     type Item = T;
     const LEN: usize = N;
 
@@ -80,12 +134,13 @@ where
     }
 
     fn push(self, value: Self::Item) -> [Self::Item; Self::LEN + 1] {
-        let mut data: [_; Self::LEN + 1] = MaybeUninit::uninit_array();
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<T>; N + 1] = MaybeUninit::uninit_array();
 
-        let mut pos = 0;
-        while pos < Self::LEN {
-            data[pos] = MaybeUninit::new(self[pos]);
-            pos += 1
+        for pos in 0..N {
+            // Safety: move-exactly-once; `array` is a `ManuallyDrop` so the
+            // moved-out element is never dropped a second time.
+            data[pos] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
         }
         data[N] = MaybeUninit::new(value);
 
@@ -93,34 +148,35 @@ where
         unsafe { assume_init(data) }
     }
 
-    
-
     fn remove(self, index: usize) -> [Self::Item; Self::LEN - 1] {
         assert!(index < Self::LEN);
 
-        let mut data: [_; Self::LEN - 1] = MaybeUninit::uninit_array();
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<T>; N - 1] = MaybeUninit::uninit_array();
 
-        let mut pos = 0;
         let mut i = 0;
-        while pos < Self::LEN {
+        for pos in 0..N {
             if pos != index {
-                data[i] = MaybeUninit::new(self[pos]);
+                // Safety: move-exactly-once; see above.
+                data[i] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
                 i += 1;
             }
-            pos += 1;
         }
+        // The element at `index` was never moved into `data`; read it out
+        // and drop it here so it isn't leaked.
+        unsafe { drop(ptr::read(&array[index])) };
 
         // Safety: data was fully initialized
         unsafe { assume_init(data) }
     }
 
     fn reverse(self) -> [Self::Item; Self::LEN] {
-        let mut data: [_; Self::LEN] = MaybeUninit::uninit_array();
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<T>; N] = MaybeUninit::uninit_array();
 
-        let mut pos = 0;
-        while pos < Self::LEN {
-            data[Self::LEN - pos - 1] = MaybeUninit::new(self[pos]);
-            pos += 1;
+        for pos in 0..N {
+            // Safety: move-exactly-once; see `push`.
+            data[N - pos - 1] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
         }
 
         // Safety: data was fully initialized
@@ -128,23 +184,97 @@ where
     }
 
     fn split<const POS: usize>(self) -> ([Self::Item; POS], [Self::Item; Self::LEN - POS]) {
-        let mut a: [_; POS] = MaybeUninit::uninit_array();
-        let mut b: [_; Self::LEN - POS] = MaybeUninit::uninit_array();
+        let array = ManuallyDrop::new(self);
+        let mut a: [MaybeUninit<T>; POS] = MaybeUninit::uninit_array();
+        let mut b: [MaybeUninit<T>; N - POS] = MaybeUninit::uninit_array();
 
-        let mut pos = 0;
-        while pos < a.len() {
-            a[pos] = MaybeUninit::new(self[pos]);
-            pos += 1
+        for pos in 0..POS {
+            // Safety: move-exactly-once; see `push`.
+            a[pos] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
         }
-
-        while pos < Self::LEN {
-            b[pos - POS] = MaybeUninit::new(self[pos]);
-            pos += 1
+        for pos in POS..N {
+            // Safety: move-exactly-once; see `push`.
+            b[pos - POS] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
         }
 
         // Safety: both a and b were fully initialized
         unsafe { (assume_init(a), assume_init(b)) }
     }
+
+    fn chunks<const C: usize>(self) -> [[Self::Item; C]; Self::LEN / C] {
+        assert!(Self::LEN % C == 0);
+
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<[T; C]>; N / C] = MaybeUninit::uninit_array();
+
+        for pos in 0..(N / C) {
+            let mut inner: [MaybeUninit<T>; C] = MaybeUninit::uninit_array();
+            for i in 0..C {
+                // Safety: move-exactly-once; see `push`.
+                inner[i] = MaybeUninit::new(unsafe { ptr::read(&array[pos * C + i]) });
+            }
+            // Safety: inner was fully initialized
+            data[pos] = MaybeUninit::new(unsafe { assume_init(inner) });
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn concat<const M: usize>(self, other: [Self::Item; M]) -> [Self::Item; Self::LEN + M] {
+        let array = ManuallyDrop::new(self);
+        let other = ManuallyDrop::new(other);
+        let mut data: [MaybeUninit<T>; N + M] = MaybeUninit::uninit_array();
+
+        for pos in 0..N {
+            // Safety: move-exactly-once; see `push`.
+            data[pos] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
+        }
+        for pos in 0..M {
+            // Safety: move-exactly-once; see `push`.
+            data[N + pos] = MaybeUninit::new(unsafe { ptr::read(&other[pos]) });
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn insert(self, index: usize, value: Self::Item) -> [Self::Item; Self::LEN + 1] {
+        assert!(index <= Self::LEN);
+
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<T>; N + 1] = MaybeUninit::uninit_array();
+
+        for pos in 0..index {
+            // Safety: move-exactly-once; see `push`.
+            data[pos] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
+        }
+        data[index] = MaybeUninit::new(value);
+        for pos in index..N {
+            // Safety: move-exactly-once; see `push`.
+            data[pos + 1] = MaybeUninit::new(unsafe { ptr::read(&array[pos]) });
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
+
+    fn map<U, F>(self, mut f: F) -> [U; Self::LEN]
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        let array = ManuallyDrop::new(self);
+        let mut data: [MaybeUninit<U>; N] = MaybeUninit::uninit_array();
+
+        for pos in 0..N {
+            // Safety: move-exactly-once; see `push`.
+            let value = unsafe { ptr::read(&array[pos]) };
+            data[pos] = MaybeUninit::new(f(value));
+        }
+
+        // Safety: data was fully initialized
+        unsafe { assume_init(data) }
+    }
 }
 
 #[cfg(test)]
@@ -188,4 +318,40 @@ mod tests {
         assert!(eq(&a, &[1, 2]));
         assert!(eq(&b, &[3]));
     }
+
+    #[test]
+    fn chunks() {
+        let chunks = [1, 2, 3, 4].chunks::<2>();
+        assert!(eq(&chunks[0], &[1, 2]));
+        assert!(eq(&chunks[1], &[3, 4]));
+    }
+
+    #[test]
+    fn concat() {
+        assert!(eq(&[1, 2].concat([3, 4, 5]), &[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn insert() {
+        assert!(eq(&[1, 2, 4].insert(2, 3), &[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn from_fn() {
+        assert!(eq(&super::from_fn::<_, 4, _>(|i| i * 2), &[0, 2, 4, 6]));
+    }
+
+    #[test]
+    fn map() {
+        assert!(eq(&ArrayUtil::map([1, 2, 3], |x| x * 2), &[2, 4, 6]));
+    }
+
+    #[test]
+    fn non_copy() {
+        let array: [String; 3] = ["a".into(), "b".into(), "c".into()];
+        assert!(eq(&array.reverse(), &["c".into(), "b".into(), "a".into()]));
+
+        let array: [String; 3] = ["a".into(), "b".into(), "c".into()];
+        assert!(eq(&array.remove(1), &["a".into(), "c".into()]));
+    }
 }